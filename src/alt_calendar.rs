@@ -0,0 +1,262 @@
+use std::str::FromStr;
+
+use crate::calendar::Calendar;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AltCalendar {
+    Saka,
+    Islamic,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AltDate {
+    pub year: i32,
+    pub month: u8, //month starts from 0
+    pub day: u32,
+}
+
+impl FromStr for AltCalendar {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "saka" | "indian" => Ok(AltCalendar::Saka),
+            "islamic" | "hijri" => Ok(AltCalendar::Islamic),
+            _ => Err(format!(
+                "Unknown calendar system: {} (use saka or islamic)",
+                s
+            )),
+        }
+    }
+}
+
+// Day number of 1 Muharram, 1 AH in the same count as Calendar::day_number.
+const ISLAMIC_EPOCH: i64 = 227015;
+
+fn islamic_to_day_number(year: i64, month: i64, day: i64) -> i64 {
+    day + 29 * (month - 1)
+        + (6 * month - 1) / 11
+        + (year - 1) * 354
+        + (3 + 11 * year) / 30
+        + ISLAMIC_EPOCH
+        - 1
+}
+
+// Day number of January 1 of a proleptic Gregorian year, counting as
+// Calendar::day_number does. The year may be 65536, one past the last
+// Calendar year.
+fn gregorian_new_year(year: i64) -> i64 {
+    let y = year - 1;
+    365 * y + y / 4 - y / 100 + y / 400 + 1
+}
+
+impl AltCalendar {
+    pub fn month_name(&self, month: u8) -> &'static str {
+        let names = match self {
+            AltCalendar::Saka => [
+                "Chaitra",
+                "Vaishakha",
+                "Jyaishtha",
+                "Ashadha",
+                "Shravana",
+                "Bhadra",
+                "Ashvin",
+                "Kartika",
+                "Agrahayana",
+                "Pausha",
+                "Magha",
+                "Phalguna",
+            ],
+            AltCalendar::Islamic => [
+                "Muharram",
+                "Safar",
+                "Rabi I",
+                "Rabi II",
+                "Jumada I",
+                "Jumada II",
+                "Rajab",
+                "Shaban",
+                "Ramadan",
+                "Shawwal",
+                "Dhu al-Qadah",
+                "Dhu al-Hijjah",
+            ],
+        };
+        names[month as usize]
+    }
+
+    pub fn era(&self) -> &'static str {
+        match self {
+            AltCalendar::Saka => "Saka",
+            AltCalendar::Islamic => "AH",
+        }
+    }
+
+    /// Converts a day of the given month to this calendar system, or None
+    /// when it falls before the system's first year.
+    pub fn convert(&self, cal: &Calendar, day: u32) -> Option<AltDate> {
+        let date = cal.day_number(day);
+        match self {
+            AltCalendar::Saka => Self::to_saka(date),
+            AltCalendar::Islamic => Self::to_islamic(date),
+        }
+    }
+
+    // Chaitra 1 falls on Gregorian March 22, or March 21 in leap years,
+    // which is day 81 of the Gregorian year either way. Julian dates are
+    // placed by their day number, so the year starts on the same day.
+    fn to_saka(date: i64) -> Option<AltDate> {
+        let mut year = (date * 400 / 146097 + 1).max(1);
+        while year > 1 && gregorian_new_year(year) > date {
+            year -= 1;
+        }
+        while gregorian_new_year(year + 1) <= date {
+            year += 1;
+        }
+        let chaitra_1 = |year: i64| gregorian_new_year(year) + 80;
+        let start = if date >= chaitra_1(year) {
+            year
+        } else {
+            year - 1
+        };
+        // Saka 1 began in 79 AD.
+        if start < 79 {
+            return None;
+        }
+        let mut month_days = [30, 31, 31, 31, 31, 31, 30, 30, 30, 30, 30, 30];
+        if Calendar::is_gregorian_leap_year(start as u16) {
+            month_days[0] = 31;
+        }
+        let mut offset = date - chaitra_1(start);
+        let mut month = 0;
+        while offset >= month_days[month] {
+            offset -= month_days[month];
+            month += 1;
+        }
+        Some(AltDate {
+            year: (start - 78) as i32,
+            month: month as u8,
+            day: (offset + 1) as u32,
+        })
+    }
+
+    // Arithmetical (tabular) Islamic calendar; observed dates may differ by a day.
+    fn to_islamic(date: i64) -> Option<AltDate> {
+        if date < ISLAMIC_EPOCH {
+            return None;
+        }
+        let year = (30 * (date - ISLAMIC_EPOCH) + 10646).div_euclid(10631);
+        let prior_days = date - islamic_to_day_number(year, 1, 1);
+        let month = (11 * prior_days + 330) / 325;
+        let day = date - islamic_to_day_number(year, month, 1) + 1;
+        Some(AltDate {
+            year: year as i32,
+            month: (month - 1) as u8,
+            day: day as u32,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::calendar::Reform;
+
+    #[test]
+    fn check_saka() {
+        let cal = Calendar {
+            year: 2024,
+            month: 0,
+            reform: Reform::default(),
+        };
+        assert_eq!(
+            AltCalendar::Saka.convert(&cal, 1),
+            Some(AltDate {
+                year: 1945,
+                month: 9,
+                day: 11
+            })
+        );
+        let cal = Calendar {
+            year: 2024,
+            month: 2,
+            reform: Reform::default(),
+        };
+        assert_eq!(
+            AltCalendar::Saka.convert(&cal, 21),
+            Some(AltDate {
+                year: 1946,
+                month: 0,
+                day: 1
+            })
+        );
+    }
+
+    #[test]
+    fn check_saka_julian_years() {
+        // Gregorian 22 March 1700 is Julian 11 March.
+        let cal = Calendar {
+            year: 1700,
+            month: 2,
+            reform: Reform::default(),
+        };
+        assert_eq!(
+            AltCalendar::Saka.convert(&cal, 11),
+            Some(AltDate {
+                year: 1622,
+                month: 0,
+                day: 1
+            })
+        );
+        let cal = Calendar {
+            year: 1,
+            month: 1,
+            reform: Reform::Gregorian,
+        };
+        assert_eq!(AltCalendar::Saka.convert(&cal, 1), None);
+    }
+
+    #[test]
+    fn check_islamic() {
+        let cal = Calendar {
+            year: 2024,
+            month: 2,
+            reform: Reform::default(),
+        };
+        assert_eq!(
+            AltCalendar::Islamic.convert(&cal, 11),
+            Some(AltDate {
+                year: 1445,
+                month: 8,
+                day: 1
+            })
+        );
+    }
+
+    #[test]
+    fn check_islamic_follows_reform() {
+        let new_year = Some(AltDate {
+            year: 1100,
+            month: 0,
+            day: 1,
+        });
+        let julian = Calendar {
+            year: 1688,
+            month: 9,
+            reform: Reform::default(),
+        };
+        let gregorian = Calendar {
+            year: 1688,
+            month: 9,
+            reform: Reform::Gregorian,
+        };
+        assert_eq!(AltCalendar::Islamic.convert(&julian, 16), new_year);
+        assert_eq!(AltCalendar::Islamic.convert(&gregorian, 26), new_year);
+        let cal = Calendar {
+            year: 500,
+            month: 2,
+            reform: Reform::default(),
+        };
+        assert_eq!(AltCalendar::Islamic.convert(&cal, 1), None);
+    }
+}
@@ -1,10 +1,95 @@
 use chrono::{Datelike, Local};
 use colored::*;
 
+use crate::alt_calendar::AltCalendar;
+use crate::holidays::{Country, Holiday};
+use chrono::NaiveDate;
 use std::{fmt::Display, print, str::FromStr};
+/// A single month, in the Julian or Gregorian calendar depending on its
+/// [`Reform`].
 pub struct Calendar {
     pub month: u8, //month starts from 0
     pub year: u16,
+    pub reform: Reform,
+}
+
+/// When the Gregorian calendar replaced the Julian one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reform {
+    /// Gregorian rules for every year.
+    Gregorian,
+    /// Julian rules for every year.
+    Julian,
+    /// The first Gregorian date; every earlier date is Julian.
+    Cutover { year: u16, month: u8, day: u32 },
+}
+
+impl Reform {
+    /// Great Britain and its colonies: 2 September 1752 was followed by 14 September.
+    pub const BRITAIN: Reform = Reform::Cutover {
+        year: 1752,
+        month: 8,
+        day: 14,
+    };
+    /// Rome and most Catholic countries: 4 October 1582 was followed by 15 October.
+    pub const ROME: Reform = Reform::Cutover {
+        year: 1582,
+        month: 9,
+        day: 15,
+    };
+}
+
+/// The British reform of 1752, which is also what GNU cal uses. Dates from
+/// 1583 to 1752 are therefore Julian unless another reform is chosen.
+impl Default for Reform {
+    fn default() -> Self {
+        Reform::BRITAIN
+    }
+}
+
+impl FromStr for Reform {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "1752" | "gb" => return Ok(Reform::BRITAIN),
+            "1582" | "it" => return Ok(Reform::ROME),
+            "gregorian" => return Ok(Reform::Gregorian),
+            "julian" => return Ok(Reform::Julian),
+            _ => {}
+        }
+        let date = NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|_| {
+            format!(
+                "Invalid reform: {} (use 1582, 1752, gregorian, julian or the first Gregorian date as YYYY-MM-DD)",
+                s
+            )
+        })?;
+        // From March 2100 the calendars are 14 days apart; later cutovers
+        // would drop whole months, and no country switched that late.
+        if date < NaiveDate::from_ymd_opt(1582, 10, 15).unwrap()
+            || date > NaiveDate::from_ymd_opt(2100, 2, 28).unwrap()
+        {
+            return Err(
+                "The Gregorian reform must fall between 1582-10-15 and 2100-02-28".to_string(),
+            );
+        }
+        Ok(Reform::Cutover {
+            year: date.year() as u16,
+            month: date.month0() as u8,
+            day: date.day(),
+        })
+    }
+}
+
+const MONTH_DAYS: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// Switches that change what the print functions draw.
+#[derive(Default)]
+pub struct PrintOptions {
+    pub holidays: Option<Country>,
+    pub alt_calendar: Option<AltCalendar>,
+    pub json: bool,
+    pub julian: bool,
 }
 
 #[derive(Debug, PartialEq)]
@@ -19,8 +104,8 @@ pub enum DayOfWeek {
 }
 
 impl DayOfWeek {
-    fn from_day_number(day: u32) -> Self {
-        match day % 7 {
+    pub(crate) fn from_day_number(day: i64) -> Self {
+        match day.rem_euclid(7) {
             0 => DayOfWeek::Sun,
             1 => DayOfWeek::Mon,
             2 => DayOfWeek::Tue,
@@ -49,15 +134,14 @@ impl Display for DayOfWeek {
 }
 
 impl Calendar {
+    /// Today's local date as (day, month from 0, year).
     pub fn get_today() -> (u32, u8, u16) {
         let now = Local::now().date_naive();
-        let cal = Calendar {
-            year: now.year() as u16,
-            month: now.month0() as u8,
-        };
-        let today = now.day();
-        (today, cal.month, cal.year)
+        (now.day(), now.month0() as u8, now.year() as u16)
     }
+
+    /// Number of days before January 1 of this year in the proleptic
+    /// Gregorian calendar, counting from 1 AD.
     pub fn get_year_base_day(&self) -> u32 {
         let year = (self.year - 1) as u32; // the point being that the current year's days are still not added.
         let base_days_for_year = year * 365;
@@ -67,38 +151,180 @@ impl Calendar {
         base_days_for_year + leap_days_for_year - leap_misses_for_century + leap_hits_for_century
     }
 
+    pub(crate) fn is_gregorian_leap_year(year: u16) -> bool {
+        if year.is_multiple_of(100) {
+            year.is_multiple_of(400)
+        } else {
+            year.is_multiple_of(4)
+        }
+    }
+
+    fn is_julian_leap_year(year: u16) -> bool {
+        year.is_multiple_of(4)
+    }
+
+    fn month_length(month: u8, leap: bool) -> u32 {
+        if leap && month == 1 {
+            29
+        } else {
+            MONTH_DAYS[month as usize]
+        }
+    }
+
+    /// Whether the given day of this month falls before the Gregorian reform.
+    pub fn is_julian(&self, day: u32) -> bool {
+        match self.reform {
+            Reform::Gregorian => false,
+            Reform::Julian => true,
+            Reform::Cutover {
+                year,
+                month,
+                day: first,
+            } => (self.year, self.month, day) < (year, month, first),
+        }
+    }
+
     pub fn is_leap_year(&self) -> bool {
-        if self.year % 100 == 0 {
-            self.year % 400 == 0
+        let february = Calendar {
+            year: self.year,
+            month: 1,
+            reform: self.reform,
+        };
+        if february.is_julian(29) {
+            Self::is_julian_leap_year(self.year)
         } else {
-            self.year % 4 == 0
+            Self::is_gregorian_leap_year(self.year)
         }
     }
 
+    /// Number of days before the first of this month in the proleptic
+    /// Gregorian calendar, counting from 1 AD.
     pub fn get_month_base_day(&self) -> u32 {
         let year_first_day = self.get_year_base_day();
-        let month_days: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
         let month = (self.month) as usize;
-        let month_days: u32 = month_days.into_iter().take(month).sum();
-        if self.is_leap_year() && month > 1 {
+        let month_days: u32 = MONTH_DAYS.into_iter().take(month).sum();
+        if Self::is_gregorian_leap_year(self.year) && month > 1 {
             year_first_day + month_days + 1
         } else {
             year_first_day + month_days
         }
     }
 
-    #[allow(dead_code)]
+    fn julian_day_number(&self, day: u32) -> i64 {
+        let year = self.year as i64 - 1;
+        let leap = Self::is_julian_leap_year(self.year);
+        let month_days: i64 = (0..self.month)
+            .map(|m| Self::month_length(m, leap) as i64)
+            .sum();
+        // January 1, 1 AD in the Julian calendar is two days before the Gregorian one.
+        year * 365 + year / 4 + month_days + day as i64 - 2
+    }
+
+    /// Days since December 31, 1 BC of the proleptic Gregorian calendar,
+    /// honouring the reform, so that consecutive days always differ by one.
+    pub fn day_number(&self, day: u32) -> i64 {
+        if self.is_julian(day) {
+            self.julian_day_number(day)
+        } else {
+            (self.get_month_base_day() + day) as i64
+        }
+    }
+
+    /// The month and day with the given day number under the given reform,
+    /// if it falls within years 1 to 65535.
+    pub fn from_day_number(number: i64, reform: Reform) -> Option<(Calendar, u32)> {
+        let first_of = |year: i64| {
+            let cal = Calendar {
+                year: year as u16,
+                month: 0,
+                reform,
+            };
+            cal.day_number(cal.days()[0])
+        };
+        let max_year = u16::MAX as i64;
+        let mut year = (number.checked_mul(400)? / 146097 + 1).clamp(1, max_year);
+        while year > 1 && first_of(year) > number {
+            year -= 1;
+        }
+        while year < max_year && first_of(year + 1) <= number {
+            year += 1;
+        }
+        if number < first_of(year) {
+            return None;
+        }
+        let mut cal = Calendar {
+            year: year as u16,
+            month: 11,
+            reform,
+        };
+        while cal.day_number(cal.days()[0]) > number {
+            cal.month -= 1;
+        }
+        let days = cal.days();
+        let index = (number - cal.day_number(days[0])) as usize;
+        let day = *days.get(index)?;
+        Some((cal, day))
+    }
+
+    /// The days that exist in this month. This is 1 to the end of the month
+    /// except around the reform, which drops every Julian day falling on or
+    /// after the first Gregorian one, even when that is in an earlier month.
+    pub fn days(&self) -> Vec<u32> {
+        let julian_len = Self::month_length(self.month, Self::is_julian_leap_year(self.year));
+        let gregorian_len = Self::month_length(self.month, Self::is_gregorian_leap_year(self.year));
+        match self.reform {
+            Reform::Gregorian => (1..=gregorian_len).collect(),
+            Reform::Julian => (1..=julian_len).collect(),
+            Reform::Cutover { year, month, day } => {
+                let first_gregorian = Calendar {
+                    year,
+                    month,
+                    reform: self.reform,
+                }
+                .day_number(day);
+                let mut days: Vec<u32> = (1..=julian_len)
+                    .filter(|&d| self.is_julian(d) && self.julian_day_number(d) < first_gregorian)
+                    .collect();
+                days.extend((1..=gregorian_len).filter(|&d| !self.is_julian(d)));
+                days
+            }
+        }
+    }
+
+    pub fn days_in_month(&self) -> u32 {
+        self.days().len() as u32
+    }
+
+    pub fn days_in_year(&self) -> u32 {
+        let december = Calendar {
+            year: self.year,
+            month: 11,
+            reform: self.reform,
+        };
+        december.day_of_year(*december.days().last().unwrap())
+    }
+
+    /// Day of the year for the given day of this month, starting from 1.
+    pub fn day_of_year(&self, day: u32) -> u32 {
+        let first = Calendar {
+            year: self.year,
+            month: 0,
+            reform: self.reform,
+        };
+        (self.day_number(day) - first.day_number(first.days()[0]) + 1) as u32
+    }
+
     pub fn get_day_of_week(&self, day: u32) -> DayOfWeek {
-        DayOfWeek::from_day_number(self.get_month_base_day() + day)
+        DayOfWeek::from_day_number(self.day_number(day))
     }
 
     fn pad(v: u32) -> String {
         if v <= 9 {
-            format!("   ")
+            "   ".to_string()
         } else if v <= 99 {
-            format!("  ")
+            "  ".to_string()
         } else {
-            format!(" ")
+            " ".to_string()
         }
     }
 
@@ -109,54 +335,136 @@ impl Calendar {
         }
         s
     }
-    fn print_line(&self, line_no: u32) {
-        let today = Self::get_today();
+    // Position of the first cell of the given line, counting the days of the
+    // month from 1. Cells before the first day have positions of 0 or less.
+    fn line_start(&self, line_no: u32) -> i32 {
+        let first_weekday = self.day_number(self.days()[0]).rem_euclid(7) as i32;
+        (line_no * 7) as i32 - first_weekday + 1
+    }
 
-        let month_days: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
-        let mut total_days = month_days[self.month as usize];
+    fn day_at(days: &[u32], position: i32) -> Option<u32> {
+        if position <= 0 || position > days.len() as i32 {
+            None
+        } else {
+            Some(days[(position - 1) as usize])
+        }
+    }
 
-        if self.is_leap_year() && self.month == 1 {
-            total_days += 1;
+    // Width of one day cell. With --julian the day number is followed by
+    // the day of the year and the days remaining, as in "  9  40/326".
+    fn cell_width(julian: bool) -> usize {
+        if julian {
+            11
+        } else {
+            4
         }
-        let month_base = (self.get_month_base_day() % 7) as i32;
-        let mut line_no = line_no;
-        if month_base == 6 {
-            line_no += 1;
+    }
+
+    // Width of a row of `columns` months printed side by side.
+    fn row_width(columns: usize, julian: bool) -> usize {
+        columns * (7 * Self::cell_width(julian) + 2) - 2
+    }
+
+    fn months_per_row(julian: bool) -> usize {
+        if julian {
+            1
+        } else {
+            3
         }
-        let line_start = (line_no * 7) as i32 - month_base;
+    }
+
+    fn print_line(&self, line_no: u32, holidays: &[Holiday], julian: bool) {
+        let today = Self::get_today();
+        let days = self.days();
+        let days_in_year = self.days_in_year();
+        let line_start = self.line_start(line_no);
         for (j, i) in (line_start..line_start + 7).enumerate() {
-            if i > total_days as i32 || i <= 0 {
-                print!("    ");
+            let Some(day) = Self::day_at(&days, i) else {
+                print!("{}", Self::spaces(Self::cell_width(julian)));
+                continue;
+            };
+            let is_today = day == today.0 && self.month == today.1 && self.year == today.2;
+            let is_holiday = holidays.iter().any(|h| h.day == day);
+            let text = format!("{}", day);
+            let width = text.len();
+            let text = if is_holiday {
+                if is_today {
+                    text.bold().black().on_bright_red()
+                } else {
+                    text.bright_red()
+                }
             } else if j % 7 == 0 {
-                if i == today.0 as i32 && self.month == today.1 && self.year == today.2 {
-                    print!(
-                        "{}{}",
-                        Self::pad(i as u32),
-                        format!("{}", i).bold().black().on_magenta()
-                    );
+                if is_today {
+                    text.bold().black().on_magenta()
                 } else {
-                    print!("{}{}", Self::pad(i as u32), format!("{}", i).magenta());
+                    text.magenta()
                 }
-            } else if i == today.0 as i32 && self.month == today.1 && self.year == today.2 {
-                print!(
-                    "{}{}",
-                    Self::pad(i as u32),
-                    format!("{}", i).bold().black().on_cyan()
-                );
+            } else if is_today {
+                text.bold().black().on_cyan()
             } else {
-                print!("{}{}", Self::pad(i as u32), format!("{}", i).cyan());
+                text.cyan()
+            };
+            if julian {
+                let day_of_year = self.day_of_year(day);
+                let note = format!("{:>4}/{:<3}", day_of_year, days_in_year - day_of_year);
+                print!("{}{}{}", Self::spaces(3 - width), text, note.dimmed());
+            } else {
+                print!("{}{}", Self::pad(day), text);
             }
         }
     }
 
-    fn print_day_names(&self) {
-        print!(
-            "{} {}",
-            " Sun".red().bold(),
-            "Mon Tue Wed Thu Fri Sat".green().bold()
-        );
+    fn print_alt_line(&self, line_no: u32, alt: AltCalendar, julian: bool) {
+        let days = self.days();
+        let line_start = self.line_start(line_no);
+        for i in line_start..line_start + 7 {
+            let Some(alt_date) = Self::day_at(&days, i).and_then(|day| alt.convert(self, day))
+            else {
+                print!("{}", Self::spaces(Self::cell_width(julian)));
+                continue;
+            };
+            let text = format!("{}", alt_date.day);
+            let width = text.len();
+            let text = if alt_date.day == 1 {
+                text.yellow().bold()
+            } else {
+                text.dimmed()
+            };
+            if julian {
+                print!("{}{}{}", Self::spaces(3 - width), text, Self::spaces(8));
+            } else {
+                print!("{}{}", Self::pad(alt_date.day), text);
+            }
+        }
     }
-    fn print_heading_month(&self) {
+
+    fn holidays(&self, options: &PrintOptions) -> Vec<Holiday> {
+        match options.holidays {
+            Some(country) => country
+                .holidays(self.year, self.reform)
+                .into_iter()
+                .filter(|h| h.month == self.month)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    fn print_day_names(&self, julian: bool) {
+        if !julian {
+            print!(
+                "{} {}",
+                " Sun".red().bold(),
+                "Mon Tue Wed Thu Fri Sat".green().bold()
+            );
+            return;
+        }
+        let pad = Self::spaces(Self::cell_width(true) - 3);
+        print!("{}{}", "Sun".red().bold(), pad);
+        for name in ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat"] {
+            print!("{}{}", name.green().bold(), pad);
+        }
+    }
+    fn month_name(&self) -> &'static str {
         let month_names = [
             "January",
             "February",
@@ -171,102 +479,620 @@ impl Calendar {
             "November",
             "December",
         ];
+        month_names[self.month as usize]
+    }
 
-        let name_length = month_names[self.month as usize].len();
+    fn print_heading_month(&self, julian: bool) {
+        let width = Self::row_width(1, julian);
+        let name_length = self.month_name().len();
         let total_length = name_length;
-        let empty_space_left = (28 - total_length) / 2 + 1;
-        let empty_space_right = 28 - total_length - empty_space_left;
+        let empty_space_left = (width - total_length) / 2 + 1;
+        let empty_space_right = width - total_length - empty_space_left;
         print!(
             "{}{}{}",
             Self::spaces(empty_space_left),
-            month_names[self.month as usize].yellow(),
+            self.month_name().yellow(),
             Self::spaces(empty_space_right),
         );
     }
 
-    pub fn print_three_calendars(cal1: Calendar, cal2: Calendar, cal3: Calendar) {
-        cal1.print_heading_month();
-        print!("  ");
-        cal2.print_heading_month();
-        print!("  ");
-        cal3.print_heading_month();
+    fn print_side_by_side(row: &[Calendar], print_one: impl Fn(usize, &Calendar)) {
+        for (k, cal) in row.iter().enumerate() {
+            if k > 0 {
+                print!("  ");
+            }
+            print_one(k, cal);
+        }
         println!();
+    }
 
-        cal1.print_day_names();
-        print!("  ");
-        cal2.print_day_names();
-        print!("  ");
-        cal3.print_day_names();
-        println!();
+    fn print_row(row: &[Calendar], options: &PrintOptions) {
+        let julian = options.julian;
+        Self::print_side_by_side(row, |_, cal| cal.print_heading_month(julian));
+        Self::print_side_by_side(row, |_, cal| cal.print_day_names(julian));
 
+        let holidays: Vec<Vec<Holiday>> = row.iter().map(|c| c.holidays(options)).collect();
         for i in 0..6 {
-            cal1.print_line(i);
-            print!("  ");
-            cal2.print_line(i);
-            print!("  ");
-            cal3.print_line(i);
-            println!();
+            Self::print_side_by_side(row, |k, cal| cal.print_line(i, &holidays[k], julian));
         }
     }
 
-    pub fn print_entire_year(year: u16) {
-        Self::print_year_heading(year);
-        for i in 0..4 {
-            let cal1 = Calendar { year, month: i * 3 };
-            let cal2 = Calendar {
-                year,
-                month: i * 3 + 1,
-            };
-            let cal3 = Calendar {
+    /// Prints the given months side by side, three to a row, or one to a
+    /// row with the wider --julian cells.
+    pub fn print_calendars(calendars: &[Calendar], options: &PrintOptions) {
+        let per_row = Self::months_per_row(options.julian);
+        for (n, row) in calendars.chunks(per_row).enumerate() {
+            if n > 0 {
+                println!();
+            }
+            Self::print_row(row, options);
+        }
+    }
+
+    /// `count` consecutive months starting with `start`, stopping early at
+    /// the end of year 65535.
+    pub fn months_from(start: Calendar, count: usize) -> Vec<Calendar> {
+        let mut months = Vec::new();
+        let mut cal = start;
+        while months.len() + 1 < count && !(cal.year == u16::MAX && cal.month == 11) {
+            let next = cal.next_month();
+            months.push(cal);
+            cal = next;
+        }
+        if count > 0 {
+            months.push(cal);
+        }
+        months
+    }
+
+    /// The months from `from` to `to`, both included, or None when `from`
+    /// comes after `to`.
+    pub fn months_between(from: Calendar, to: &Calendar) -> Option<Vec<Calendar>> {
+        let first = from.year as usize * 12 + from.month as usize;
+        let last = to.year as usize * 12 + to.month as usize;
+        if first > last {
+            return None;
+        }
+        Some(Self::months_from(from, last - first + 1))
+    }
+
+    /// Prints a run of consecutive months under a heading naming their years.
+    pub fn print_months(months: &[Calendar], options: &PrintOptions) {
+        if options.json {
+            Self::print_json(months, options);
+            return;
+        }
+        let (Some(first), Some(last)) = (months.first(), months.last()) else {
+            return;
+        };
+        let heading = if first.year == last.year {
+            first.year.to_string()
+        } else {
+            format!("{} - {}", first.year, last.year)
+        };
+        Self::print_year_heading(&heading, months.len(), options.julian);
+        Self::print_calendars(months, options);
+    }
+
+    /// Prints all twelve months of a year, three to a row.
+    pub fn print_entire_year(year: u16, reform: Reform, options: &PrintOptions) {
+        let months: Vec<Calendar> = (0..12)
+            .map(|month| Calendar {
                 year,
-                month: i * 3 + 2,
-            };
-            Self::print_three_calendars(cal1, cal2, cal3);
-            println!();
+                month,
+                reform,
+            })
+            .collect();
+        if options.json {
+            Self::print_json(&months, options);
+            return;
         }
+        Self::print_year_heading(&year.to_string(), 3, options.julian);
+        Self::print_calendars(&months, options);
+        println!();
     }
 
-    fn prev_month(&self) -> Calendar {
+    pub fn prev_month(&self) -> Calendar {
         if self.month == 0 {
             Calendar {
                 year: self.year - 1,
                 month: 11,
+                reform: self.reform,
             }
         } else {
             Calendar {
                 year: self.year,
                 month: self.month - 1,
+                reform: self.reform,
             }
         }
     }
 
-    fn next_month(&self) -> Calendar {
+    pub fn next_month(&self) -> Calendar {
         if self.month == 11 {
             Calendar {
                 year: self.year + 1,
                 month: 0,
+                reform: self.reform,
             }
         } else {
             Calendar {
                 year: self.year,
                 month: self.month + 1,
+                reform: self.reform,
             }
         }
     }
-    fn print_year_heading(year: u16) {
-        let space_on_each_side = 42;
+    // Centres the heading over a row of `columns` months.
+    fn print_year_heading(heading: &str, columns: usize, julian: bool) {
+        let width = Self::row_width(columns.min(Self::months_per_row(julian)), julian);
+        let space_on_each_side = width.saturating_sub(heading.len()) / 2;
         print!("{}", Self::spaces(space_on_each_side));
-        print!("{}", year.to_string().bold().bright_yellow());
+        print!("{}", heading.bold().bright_yellow());
         print!("{}", Self::spaces(space_on_each_side));
         println!();
         println!();
     }
 
-    pub fn print(self) {
+    fn print_centered(text: ColoredString, julian: bool) {
+        let empty_space_left = Self::row_width(1, julian).saturating_sub(text.len()) / 2 + 1;
+        println!("{}{}", Self::spaces(empty_space_left), text);
+    }
+
+    fn print_alt_heading(&self, alt: AltCalendar, julian: bool) {
+        let converted: Vec<_> = self
+            .days()
+            .iter()
+            .filter_map(|&day| alt.convert(self, day))
+            .collect();
+        let (Some(first), Some(last)) = (converted.first(), converted.last()) else {
+            return;
+        };
+        let heading = if first.year != last.year {
+            format!(
+                "{} {} - {} {} {}",
+                alt.month_name(first.month),
+                first.year,
+                alt.month_name(last.month),
+                last.year,
+                alt.era()
+            )
+        } else if first.month != last.month {
+            format!(
+                "{} - {} {} {}",
+                alt.month_name(first.month),
+                alt.month_name(last.month),
+                last.year,
+                alt.era()
+            )
+        } else {
+            format!(
+                "{} {} {}",
+                alt.month_name(first.month),
+                first.year,
+                alt.era()
+            )
+        };
+        Self::print_centered(heading.dimmed(), julian);
+    }
+
+    fn json_string(s: &str) -> String {
+        let mut out = String::from("\"");
+        for c in s.chars() {
+            match c {
+                '"' => out += "\\\"",
+                '\\' => out += "\\\\",
+                c if (c as u32) < 0x20 => out += &format!("\\u{:04x}", c as u32),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+        out
+    }
+
+    /// Month grid metadata as a JSON object. Months are numbered from 1 here,
+    /// including those of alternate calendar dates, and weeks are rows of seven days starting on Sunday with null padding.
+    pub fn to_json(&self, options: &PrintOptions) -> String {
+        let today = Self::get_today();
+        let days = self.days();
+        let total_days = days.len() as u32;
+        let today = if self.month == today.1 && self.year == today.2 {
+            today.0.to_string()
+        } else {
+            "null".to_string()
+        };
+
+        let mut weeks = Vec::new();
+        for i in 0..6 {
+            let line_start = self.line_start(i);
+            if line_start > total_days as i32 {
+                break;
+            }
+            let cells: Vec<String> = (line_start..line_start + 7)
+                .map(|i| match Self::day_at(&days, i) {
+                    Some(day) => day.to_string(),
+                    None => "null".to_string(),
+                })
+                .collect();
+            weeks.push(format!("[{}]", cells.join(",")));
+        }
+
+        let mut json = format!(
+            "{{\"year\":{},\"month\":{},\"name\":{},\"days\":{},\"first_weekday\":{},\"today\":{},\"weeks\":[{}]",
+            self.year,
+            self.month + 1,
+            Self::json_string(self.month_name()),
+            total_days,
+            Self::json_string(&self.get_day_of_week(days[0]).to_string()),
+            today,
+            weeks.join(",")
+        );
+        if options.holidays.is_some() {
+            let holidays: Vec<String> = self
+                .holidays(options)
+                .iter()
+                .map(|h| {
+                    format!(
+                        "{{\"day\":{},\"name\":{}}}",
+                        h.day,
+                        Self::json_string(h.name)
+                    )
+                })
+                .collect();
+            json += &format!(",\"holidays\":[{}]", holidays.join(","));
+        }
+        if let Some(alt) = options.alt_calendar {
+            let alt_days: Vec<String> = days
+                .iter()
+                .map(|&d| match alt.convert(self, d) {
+                    Some(alt_date) => format!(
+                        "{{\"year\":{},\"month\":{},\"month_name\":{},\"day\":{}}}",
+                        alt_date.year,
+                        alt_date.month + 1,
+                        Self::json_string(alt.month_name(alt_date.month)),
+                        alt_date.day
+                    ),
+                    None => "null".to_string(),
+                })
+                .collect();
+            json += &format!(",\"alt_days\":[{}]", alt_days.join(","));
+        }
+        if options.julian {
+            let days_of_year: Vec<String> = days
+                .iter()
+                .map(|&d| self.day_of_year(d).to_string())
+                .collect();
+            json += &format!(
+                ",\"days_in_year\":{},\"day_of_year\":[{}]",
+                self.days_in_year(),
+                days_of_year.join(",")
+            );
+        }
+        json.push('}');
+        json
+    }
+
+    fn html_escape(s: &str) -> String {
+        s.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+
+    /// A standalone HTML page with this month as a table, one row per week.
+    /// Holiday names, days of the year and alternate calendar dates are
+    /// written into the day cells when the options ask for them.
+    pub fn to_html(&self, options: &PrintOptions) -> String {
+        let title = format!("{} {}", self.month_name(), self.year);
+        let today = Self::get_today();
+        let days = self.days();
+        let holidays = self.holidays(options);
+
+        let mut html = String::new();
+        html += "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n";
+        html += &format!("<title>{}</title>\n", title);
+        html += "<style>\n\
+                 body { font-family: sans-serif; margin: 2em; }\n\
+                 table { border-collapse: collapse; width: 100%; table-layout: fixed; }\n\
+                 th, td { border: 1px solid #999; padding: 0.3em; vertical-align: top; }\n\
+                 th { background: #eee; }\n\
+                 td { height: 6em; }\n\
+                 .day { font-weight: bold; }\n\
+                 .sun .day, th.sun { color: #a0a; }\n\
+                 .holiday .day { color: #c00; }\n\
+                 .today { background: #dff4f7; }\n\
+                 .note { font-size: 0.8em; color: #555; }\n\
+                 </style>\n</head>\n<body>\n";
+        html += &format!("<h1>{}</h1>\n<table>\n<tr>", title);
+        for (j, name) in ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]
+            .iter()
+            .enumerate()
+        {
+            if j == 0 {
+                html += &format!("<th class=\"sun\">{}</th>", name);
+            } else {
+                html += &format!("<th>{}</th>", name);
+            }
+        }
+        html += "</tr>\n";
+
+        for i in 0..6 {
+            let line_start = self.line_start(i);
+            if line_start > days.len() as i32 {
+                break;
+            }
+            html += "<tr>";
+            for (j, position) in (line_start..line_start + 7).enumerate() {
+                let Some(day) = Self::day_at(&days, position) else {
+                    html += "<td></td>";
+                    continue;
+                };
+                let holiday = holidays.iter().find(|h| h.day == day);
+                let mut classes = Vec::new();
+                if j == 0 {
+                    classes.push("sun");
+                }
+                if holiday.is_some() {
+                    classes.push("holiday");
+                }
+                if day == today.0 && self.month == today.1 && self.year == today.2 {
+                    classes.push("today");
+                }
+                if classes.is_empty() {
+                    html += "<td>";
+                } else {
+                    html += &format!("<td class=\"{}\">", classes.join(" "));
+                }
+                html += &format!("<div class=\"day\">{}</div>", day);
+                if let Some(holiday) = holiday {
+                    html += &format!(
+                        "<div class=\"note\">{}</div>",
+                        Self::html_escape(holiday.name)
+                    );
+                }
+                if options.julian {
+                    html += &format!("<div class=\"note\">Day {}</div>", self.day_of_year(day));
+                }
+                if let Some(alt) = options.alt_calendar {
+                    if let Some(alt_date) = alt.convert(self, day) {
+                        html += &format!(
+                            "<div class=\"note\">{} {}</div>",
+                            alt_date.day,
+                            Self::html_escape(alt.month_name(alt_date.month))
+                        );
+                    }
+                }
+                html += "</td>";
+            }
+            html += "</tr>\n";
+        }
+        html += "</table>\n</body>\n</html>\n";
+        html
+    }
+
+    // Text for the standard Type 1 fonts, which use WinAnsiEncoding; this
+    // matches Latin-1 for every character the grid shows.
+    #[cfg(feature = "pdf")]
+    fn pdf_text(s: &str) -> Vec<u8> {
+        s.chars()
+            .map(|c| if (c as u32) < 256 { c as u8 } else { b'?' })
+            .collect()
+    }
+
+    /// Renders the months as a PDF with one landscape A4 page per month,
+    /// carrying the same notes as [`Calendar::to_html`] in each cell.
+    #[cfg(feature = "pdf")]
+    pub fn to_pdf(calendars: &[Calendar], options: &PrintOptions) -> Vec<u8> {
+        use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str};
+
+        const WIDTH: f32 = 842.0;
+        const HEIGHT: f32 = 595.0;
+        const MARGIN: f32 = 36.0;
+        const TITLE_HEIGHT: f32 = 48.0;
+        const HEADER_HEIGHT: f32 = 20.0;
+
+        let catalog_id = Ref::new(1);
+        let page_tree_id = Ref::new(2);
+        let font_id = Ref::new(3);
+        let bold_font_id = Ref::new(4);
+        let page_ids: Vec<Ref> = (0..calendars.len())
+            .map(|i| Ref::new(5 + 2 * i as i32))
+            .collect();
+
+        let mut pdf = Pdf::new();
+        pdf.catalog(catalog_id).pages(page_tree_id);
+        pdf.pages(page_tree_id)
+            .kids(page_ids.iter().copied())
+            .count(page_ids.len() as i32);
+        pdf.type1_font(font_id)
+            .base_font(Name(b"Helvetica"))
+            .encoding_predefined(Name(b"WinAnsiEncoding"));
+        pdf.type1_font(bold_font_id)
+            .base_font(Name(b"Helvetica-Bold"))
+            .encoding_predefined(Name(b"WinAnsiEncoding"));
+
+        let grid_width = WIDTH - 2.0 * MARGIN;
+        let cell_width = grid_width / 7.0;
+        let grid_top = HEIGHT - MARGIN - TITLE_HEIGHT;
+
+        for (cal, &page_id) in calendars.iter().zip(&page_ids) {
+            let content_id = Ref::new(page_id.get() + 1);
+            let days = cal.days();
+            let holidays = cal.holidays(options);
+            let weeks = (0..6)
+                .take_while(|&i| cal.line_start(i) <= days.len() as i32)
+                .count();
+            let cell_height = (grid_top - HEADER_HEIGHT - MARGIN) / weeks as f32;
+
+            let mut content = Content::new();
+            let title = format!("{} {}", cal.month_name(), cal.year);
+            content
+                .begin_text()
+                .set_font(Name(b"F2"), 24.0)
+                .next_line(MARGIN, HEIGHT - MARGIN - 24.0)
+                .show(Str(&Self::pdf_text(&title)))
+                .end_text();
+
+            content.set_fill_gray(0.93);
+            content
+                .rect(MARGIN, grid_top - HEADER_HEIGHT, grid_width, HEADER_HEIGHT)
+                .fill_nonzero();
+            for (j, name) in ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]
+                .iter()
+                .enumerate()
+            {
+                if j == 0 {
+                    content.set_fill_rgb(0.63, 0.0, 0.63);
+                } else {
+                    content.set_fill_gray(0.0);
+                }
+                content
+                    .begin_text()
+                    .set_font(Name(b"F2"), 11.0)
+                    .next_line(
+                        MARGIN + j as f32 * cell_width + 6.0,
+                        grid_top - HEADER_HEIGHT + 6.0,
+                    )
+                    .show(Str(name.as_bytes()))
+                    .end_text();
+            }
+
+            for i in 0..weeks {
+                let line_start = cal.line_start(i as u32);
+                let top = grid_top - HEADER_HEIGHT - i as f32 * cell_height;
+                for (j, position) in (line_start..line_start + 7).enumerate() {
+                    let left = MARGIN + j as f32 * cell_width;
+                    let Some(day) = Self::day_at(&days, position) else {
+                        continue;
+                    };
+                    let holiday = holidays.iter().find(|h| h.day == day);
+                    let mut notes = Vec::new();
+                    if let Some(holiday) = holiday {
+                        notes.push(holiday.name.to_string());
+                    }
+                    if options.julian {
+                        notes.push(format!("Day {}", cal.day_of_year(day)));
+                    }
+                    if let Some(alt) = options.alt_calendar {
+                        if let Some(alt_date) = alt.convert(cal, day) {
+                            notes.push(format!(
+                                "{} {}",
+                                alt_date.day,
+                                alt.month_name(alt_date.month)
+                            ));
+                        }
+                    }
+
+                    // Keep long notes inside their own cell.
+                    content.save_state();
+                    content
+                        .rect(left, top - cell_height, cell_width, cell_height)
+                        .clip_nonzero()
+                        .end_path();
+                    if holiday.is_some() {
+                        content.set_fill_rgb(0.8, 0.0, 0.0);
+                    } else if j == 0 {
+                        content.set_fill_rgb(0.63, 0.0, 0.63);
+                    } else {
+                        content.set_fill_gray(0.0);
+                    }
+                    content
+                        .begin_text()
+                        .set_font(Name(b"F2"), 14.0)
+                        .next_line(left + 5.0, top - 17.0)
+                        .show(Str(day.to_string().as_bytes()))
+                        .end_text();
+                    content.set_fill_gray(0.33);
+                    for (k, note) in notes.iter().enumerate() {
+                        content
+                            .begin_text()
+                            .set_font(Name(b"F1"), 8.0)
+                            .next_line(left + 5.0, top - 30.0 - 10.0 * k as f32)
+                            .show(Str(&Self::pdf_text(note)))
+                            .end_text();
+                    }
+                    content.restore_state();
+                }
+            }
+
+            content.set_stroke_gray(0.6).set_line_width(0.75);
+            content
+                .rect(MARGIN, grid_top - HEADER_HEIGHT, grid_width, HEADER_HEIGHT)
+                .stroke();
+            for i in 0..weeks {
+                for j in 0..7 {
+                    content
+                        .rect(
+                            MARGIN + j as f32 * cell_width,
+                            grid_top - HEADER_HEIGHT - (i + 1) as f32 * cell_height,
+                            cell_width,
+                            cell_height,
+                        )
+                        .stroke();
+                }
+            }
+
+            let mut page = pdf.page(page_id);
+            page.parent(page_tree_id)
+                .media_box(Rect::new(0.0, 0.0, WIDTH, HEIGHT))
+                .contents(content_id);
+            page.resources()
+                .fonts()
+                .pair(Name(b"F1"), font_id)
+                .pair(Name(b"F2"), bold_font_id);
+            page.finish();
+            pdf.stream(content_id, &content.finish());
+        }
+        pdf.finish()
+    }
+
+    /// Prints the given months as `{"months": [...]}`.
+    pub fn print_json(calendars: &[Calendar], options: &PrintOptions) {
+        let months: Vec<String> = calendars.iter().map(|c| c.to_json(options)).collect();
+        println!("{{\"months\":[{}]}}", months.join(","));
+    }
+
+    /// Prints this month alone, with the alternate calendar underneath if set.
+    pub fn print_single_month(self, options: &PrintOptions) {
+        if options.json {
+            Self::print_json(&[self], options);
+            return;
+        }
+        let julian = options.julian;
+        Self::print_centered(
+            format!("{} {}", self.month_name(), self.year).yellow(),
+            julian,
+        );
+        if let Some(alt) = options.alt_calendar {
+            self.print_alt_heading(alt, julian);
+        }
+        self.print_day_names(julian);
+        println!();
+
+        let holidays = self.holidays(options);
+        for i in 0..6 {
+            if self.line_start(i) > self.days_in_month() as i32 {
+                break;
+            }
+            self.print_line(i, &holidays, julian);
+            println!();
+            if let Some(alt) = options.alt_calendar {
+                self.print_alt_line(i, alt, julian);
+                println!();
+            }
+        }
+    }
+
+    /// Prints this month between the previous and the next one.
+    pub fn print(self, options: &PrintOptions) {
         let prev_month = self.prev_month();
         let next_month = self.next_month();
-        Self::print_year_heading(self.year);
-        Self::print_three_calendars(prev_month, self, next_month);
+        if options.json {
+            Self::print_json(&[prev_month, self, next_month], options);
+            return;
+        }
+        Self::print_year_heading(&self.year.to_string(), 3, options.julian);
+        Self::print_calendars(&[prev_month, self, next_month], options);
     }
 }
 #[cfg(test)]
@@ -274,21 +1100,33 @@ mod test {
     use super::*;
     #[test]
     fn check_first_year() {
-        let calendar = Calendar { year: 1, month: 1 };
+        let calendar = Calendar {
+            year: 1,
+            month: 1,
+            reform: Reform::default(),
+        };
         assert_eq!(calendar.get_year_base_day(), 0);
         assert_eq!(calendar.get_month_base_day(), 31);
     }
 
     #[test]
     fn check_leap_year() {
-        let calendar = Calendar { year: 4, month: 1 };
+        let calendar = Calendar {
+            year: 4,
+            month: 1,
+            reform: Reform::default(),
+        };
         assert_eq!(calendar.get_year_base_day(), 365 * 3);
         assert_eq!(calendar.get_month_base_day(), 365 * 3 + 31);
     }
 
     #[test]
     fn check_leap_year_high_month() {
-        let calendar = Calendar { year: 4, month: 3 };
+        let calendar = Calendar {
+            year: 4,
+            month: 3,
+            reform: Reform::default(),
+        };
         assert_eq!(calendar.get_year_base_day(), 365 * 3);
         assert_eq!(calendar.get_month_base_day(), 365 * 3 + 31 + 29 + 31);
     }
@@ -298,6 +1136,7 @@ mod test {
         let calendar = Calendar {
             year: 2022,
             month: 6,
+            reform: Reform::default(),
         };
         assert_eq!(calendar.get_day_of_week(3), DayOfWeek::Sun);
     }
@@ -307,6 +1146,7 @@ mod test {
         let calendar = Calendar {
             year: 2022,
             month: 5,
+            reform: Reform::default(),
         };
         assert_eq!(calendar.get_day_of_week(27), DayOfWeek::Mon);
     }
@@ -316,15 +1156,218 @@ mod test {
         let calendar = Calendar {
             year: 2020,
             month: 5,
+            reform: Reform::default(),
         };
         assert_eq!(calendar.get_day_of_week(9), DayOfWeek::Tue);
     }
 
+    #[test]
+    fn check_day_of_year() {
+        let calendar = Calendar {
+            year: 2024,
+            month: 11,
+            reform: Reform::default(),
+        };
+        assert_eq!(calendar.day_of_year(31), 366);
+        let calendar = Calendar {
+            year: 2023,
+            month: 2,
+            reform: Reform::default(),
+        };
+        assert_eq!(calendar.day_of_year(1), 60);
+    }
+
+    #[test]
+    fn check_reform_month() {
+        let calendar = Calendar {
+            year: 1752,
+            month: 8,
+            reform: Reform::default(),
+        };
+        let mut days = vec![1, 2];
+        days.extend(14..=30);
+        assert_eq!(calendar.days(), days);
+        assert_eq!(calendar.get_day_of_week(2), DayOfWeek::Wed);
+        assert_eq!(calendar.get_day_of_week(14), DayOfWeek::Thu);
+        assert_eq!(calendar.days_in_year(), 355);
+    }
+
+    #[test]
+    fn check_julian_leap_year() {
+        let calendar = Calendar {
+            year: 1700,
+            month: 1,
+            reform: Reform::default(),
+        };
+        assert_eq!(calendar.days_in_month(), 29);
+        assert_eq!(calendar.get_day_of_week(29), DayOfWeek::Thu);
+    }
+
+    #[test]
+    fn check_default_reform() {
+        // Baseline printed 1583 to 1752 as Gregorian; the default is now Julian there.
+        assert_eq!(Reform::default(), Reform::BRITAIN);
+        let julian = Calendar {
+            year: 1700,
+            month: 1,
+            reform: Reform::default(),
+        };
+        let gregorian = Calendar {
+            year: 1700,
+            month: 1,
+            reform: Reform::Gregorian,
+        };
+        assert_eq!(julian.days_in_month(), 29);
+        assert_eq!(gregorian.days_in_month(), 28);
+    }
+
+    #[test]
+    fn check_reform_at_month_start() {
+        // Sweden went from 17 February 1753 to 1 March 1753.
+        let reform: Reform = "1753-03-01".parse().unwrap();
+        let february = Calendar {
+            year: 1753,
+            month: 1,
+            reform,
+        };
+        let march = Calendar {
+            year: 1753,
+            month: 2,
+            reform,
+        };
+        assert_eq!(february.days(), (1..=17).collect::<Vec<u32>>());
+        assert_eq!(february.day_number(17) + 1, march.day_number(1));
+        assert_eq!(february.days_in_year(), 354);
+        assert!("2100-03-01".parse::<Reform>().is_err());
+    }
+
+    fn months(start: (u16, u8), count: usize) -> Vec<(u16, u8)> {
+        let start = Calendar {
+            year: start.0,
+            month: start.1,
+            reform: Reform::default(),
+        };
+        Calendar::months_from(start, count)
+            .iter()
+            .map(|c| (c.year, c.month))
+            .collect()
+    }
+
+    #[test]
+    fn check_months_from() {
+        assert_eq!(
+            months((2024, 10), 4),
+            vec![(2024, 10), (2024, 11), (2025, 0), (2025, 1)]
+        );
+        assert_eq!(months((2024, 10), 0), vec![]);
+        // Stops at December 65535 however many months are asked for.
+        let tail = months((u16::MAX - 1, 11), 24);
+        assert_eq!(tail.len(), 13);
+        assert_eq!(tail.first(), Some(&(u16::MAX - 1, 11)));
+        assert_eq!(tail.last(), Some(&(u16::MAX, 11)));
+    }
+
+    #[test]
+    fn check_months_between() {
+        let month = |year, month| Calendar {
+            year,
+            month,
+            reform: Reform::default(),
+        };
+        let span = Calendar::months_between(month(2024, 10), &month(2025, 1)).unwrap();
+        assert_eq!(span.len(), 4);
+        assert_eq!((span[3].year, span[3].month), (2025, 1));
+        assert_eq!(
+            Calendar::months_between(month(2024, 5), &month(2024, 5))
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(Calendar::months_between(month(2025, 0), &month(2024, 11)).is_none());
+    }
+
+    #[test]
+    fn check_month_json() {
+        let calendar = Calendar {
+            year: 2022,
+            month: 6,
+            reform: Reform::default(),
+        };
+        let json = calendar.to_json(&PrintOptions::default());
+        assert!(json.starts_with(
+            "{\"year\":2022,\"month\":7,\"name\":\"July\",\"days\":31,\"first_weekday\":\"Fri\",\"today\":null,"
+        ));
+        assert!(json.contains("\"weeks\":[[null,null,null,null,null,1,2],"));
+        assert!(json.ends_with("[31,null,null,null,null,null,null]]}"));
+    }
+
+    #[test]
+    fn check_alt_month_json() {
+        let calendar = Calendar {
+            year: 2024,
+            month: 2,
+            reform: Reform::default(),
+        };
+        let options = PrintOptions {
+            alt_calendar: Some(AltCalendar::Islamic),
+            ..Default::default()
+        };
+        let json = calendar.to_json(&options);
+        assert!(json.contains(
+            "\"alt_days\":[{\"year\":1445,\"month\":8,\"month_name\":\"Shaban\",\"day\":20},"
+        ));
+    }
+
+    #[test]
+    fn check_month_html() {
+        let calendar = Calendar {
+            year: 2022,
+            month: 6,
+            reform: Reform::default(),
+        };
+        let options = PrintOptions {
+            holidays: Some(Country::Us),
+            ..Default::default()
+        };
+        let html = calendar.to_html(&options);
+        assert!(html.contains("<title>July 2022</title>"));
+        assert!(html.contains(
+            "<tr><td></td><td></td><td></td><td></td><td></td><td><div class=\"day\">1</div></td>"
+        ));
+        assert!(html.contains(
+            "<td class=\"holiday\"><div class=\"day\">4</div><div class=\"note\">Independence Day</div></td>"
+        ));
+    }
+
+    #[cfg(feature = "pdf")]
+    #[test]
+    fn check_year_pdf() {
+        let months = Calendar::months_from(
+            Calendar {
+                year: 2022,
+                month: 0,
+                reform: Reform::default(),
+            },
+            12,
+        );
+        let options = PrintOptions {
+            holidays: Some(Country::Us),
+            ..Default::default()
+        };
+        let pdf = Calendar::to_pdf(&months, &options);
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(pdf.starts_with(b"%PDF-"));
+        assert!(text.contains("/Count 12"));
+        assert!(text.contains("(July 2022) Tj"));
+        assert!(text.contains("(Independence Day) Tj"));
+    }
+
     #[test]
     fn check_day_of_week_leap_2() {
         let calendar = Calendar {
             year: 2020,
             month: 0,
+            reform: Reform::default(),
         };
         assert_eq!(calendar.get_day_of_week(15), DayOfWeek::Wed);
     }
@@ -0,0 +1,60 @@
+// Names on the command line. main.rs parses with these and the completion
+// scripts are tested against the tables, so a new flag or subcommand goes
+// here first.
+
+pub const JSON: &str = "--json";
+pub const JULIAN: &str = "--julian";
+pub const BUSINESS: &str = "--business";
+pub const HOLIDAYS: &str = "--holidays";
+pub const REFORM: &str = "--reform";
+pub const ALT: &str = "--alt";
+pub const MONTHS: &str = "--months";
+pub const FROM: &str = "--from";
+pub const TO: &str = "--to";
+pub const FORMAT: &str = "--format";
+pub const MONTH: &str = "--month";
+pub const YEAR: &str = "--year";
+pub const OUTPUT_SHORT: &str = "-o";
+pub const OUTPUT: &str = "--output";
+
+pub const FLAGS: &[&str] = &[
+    JSON,
+    JULIAN,
+    BUSINESS,
+    HOLIDAYS,
+    REFORM,
+    ALT,
+    MONTHS,
+    FROM,
+    TO,
+    FORMAT,
+    MONTH,
+    YEAR,
+    OUTPUT_SHORT,
+    OUTPUT,
+];
+
+pub const COMPLETIONS: &str = "completions";
+pub const DIFF: &str = "diff";
+pub const SHIFT: &str = "shift";
+pub const EXPORT: &str = "export";
+
+pub const SUBCOMMANDS: &[&str] = &[COMPLETIONS, DIFF, SHIFT, EXPORT];
+
+/// The first argument that looks like a flag but is not in [`FLAGS`].
+/// Negative offsets such as -2w are not flags.
+pub fn unknown_flag(args: &[String]) -> Option<&str> {
+    args.iter().map(String::as_str).find(|a| {
+        a.starts_with('-')
+            && !a[1..].starts_with(|c: char| c.is_ascii_digit())
+            && !FLAGS.contains(a)
+    })
+}
+
+/// The first positional argument when it is neither a number nor in
+/// [`SUBCOMMANDS`]. Flags must already be removed from `args`.
+pub fn unknown_subcommand(args: &[String]) -> Option<&str> {
+    args.first()
+        .map(String::as_str)
+        .filter(|a| !a.starts_with(|c: char| c.is_ascii_digit()) && !SUBCOMMANDS.contains(a))
+}
@@ -0,0 +1,139 @@
+const BASH: &str = r#"_calendar() {
+    local cur prev
+    cur="${COMP_WORDS[COMP_CWORD]}"
+    prev="${COMP_WORDS[COMP_CWORD-1]}"
+    case "$prev" in
+        --holidays)
+            COMPREPLY=($(compgen -W "us gb de in" -- "$cur"))
+            return
+            ;;
+        --alt)
+            COMPREPLY=($(compgen -W "saka islamic" -- "$cur"))
+            return
+            ;;
+        --reform)
+            COMPREPLY=($(compgen -W "1582 1752 gregorian julian" -- "$cur"))
+            return
+            ;;
+        completions)
+            COMPREPLY=($(compgen -W "bash zsh fish" -- "$cur"))
+            return
+            ;;
+        --format)
+            COMPREPLY=($(compgen -W "html pdf" -- "$cur"))
+            return
+            ;;
+        -o|--output)
+            COMPREPLY=($(compgen -f -- "$cur"))
+            return
+            ;;
+    esac
+    if [[ "$cur" == -* ]]; then
+        COMPREPLY=($(compgen -W "--json --julian --reform --holidays --alt --months --from --to --business --format --month --year --output" -- "$cur"))
+    elif [[ $COMP_CWORD -eq 1 ]]; then
+        COMPREPLY=($(compgen -W "completions diff shift export" -- "$cur"))
+    fi
+}
+complete -F _calendar calendar
+"#;
+
+const ZSH: &str = r#"#compdef calendar
+
+local -a commands
+commands=(
+    'completions:print a shell completion script'
+    'diff:count the days between two dates'
+    'shift:move a date by an offset'
+    'export:write a month or year as HTML or PDF'
+)
+
+if (( CURRENT == 3 )) && [[ $words[2] == completions ]]; then
+    _values 'shell' bash zsh fish
+    return
+fi
+
+_arguments \
+    '--json[print months as JSON]' \
+    '--julian[show the day of the year under each date]' \
+    '--reform[date of the switch to the Gregorian calendar]:reform:(1582 1752 gregorian julian)' \
+    '--holidays[colour public holidays]:country:(us gb de in)' \
+    '--alt[show an alternate calendar under each date]:system:(saka islamic)' \
+    '--months[number of months to print]:count:' \
+    '--from[first month to print]:month (YYYY-MM):' \
+    '--to[last month to print]:month (YYYY-MM):' \
+    '--business[count only working days in diff]' \
+    '--format[export format]:format:(html pdf)' \
+    '--month[month to export]:month (YYYY-MM):' \
+    '--year[year to export as PDF]:year:' \
+    '(-o --output)'{-o,--output}'[file to export to]:file:_files' \
+    '1:year or command:{_describe command commands}' \
+    '2:month:(1 2 3 4 5 6 7 8 9 10 11 12)'
+"#;
+
+const FISH: &str = r#"complete -c calendar -f
+complete -c calendar -l json -d 'Print months as JSON'
+complete -c calendar -l julian -d 'Show the day of the year under each date'
+complete -c calendar -l reform -x -a '1582 1752 gregorian julian' -d 'Date of the switch to the Gregorian calendar'
+complete -c calendar -l holidays -x -a 'us gb de in' -d 'Colour public holidays'
+complete -c calendar -l alt -x -a 'saka islamic' -d 'Show an alternate calendar under each date'
+complete -c calendar -l months -x -d 'Number of months to print'
+complete -c calendar -l from -x -d 'First month to print (YYYY-MM)'
+complete -c calendar -l to -x -d 'Last month to print (YYYY-MM)'
+complete -c calendar -l business -d 'Count only working days in diff'
+complete -c calendar -l format -x -a 'html pdf' -d 'Export format'
+complete -c calendar -l month -x -d 'Month to export (YYYY-MM)'
+complete -c calendar -l year -x -d 'Year to export as PDF'
+complete -c calendar -s o -l output -r -F -d 'File to export to'
+complete -c calendar -n '__fish_use_subcommand' -a export -d 'Write a month or year as HTML or PDF'
+complete -c calendar -n '__fish_use_subcommand' -a completions -d 'Print a shell completion script'
+complete -c calendar -n '__fish_use_subcommand' -a diff -d 'Count the days between two dates'
+complete -c calendar -n '__fish_use_subcommand' -a shift -d 'Move a date by an offset'
+complete -c calendar -n '__fish_seen_subcommand_from completions' -a 'bash zsh fish'
+"#;
+
+pub fn script(shell: &str) -> Option<&'static str> {
+    match shell {
+        "bash" => Some(BASH),
+        "zsh" => Some(ZSH),
+        "fish" => Some(FISH),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::cli::{FLAGS, SUBCOMMANDS};
+
+    fn has_word(script: &str, word: &str) -> bool {
+        script
+            .split(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+            .any(|w| w == word)
+    }
+
+    #[test]
+    fn check_scripts_cover_cli() {
+        for flag in FLAGS {
+            assert!(has_word(BASH, flag), "bash is missing {}", flag);
+            assert!(has_word(ZSH, flag), "zsh is missing {}", flag);
+            let fish = match flag.strip_prefix("--") {
+                Some(long) => format!("-l {} ", long),
+                None => format!("-s {} ", &flag[1..]),
+            };
+            assert!(FISH.contains(&fish), "fish is missing {}", flag);
+        }
+        for name in SUBCOMMANDS {
+            assert!(has_word(BASH, name), "bash is missing {}", name);
+            assert!(
+                ZSH.contains(&format!("'{}:", name)),
+                "zsh is missing {}",
+                name
+            );
+            assert!(
+                FISH.contains(&format!("-a {} ", name)),
+                "fish is missing {}",
+                name
+            );
+        }
+    }
+}
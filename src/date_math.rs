@@ -0,0 +1,328 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::calendar::{Calendar, DayOfWeek, Reform};
+use crate::holidays::Country;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8, //month starts from 0
+    pub day: u32,
+}
+
+/// An amount to move a date by, as accepted by `calendar shift`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Offset {
+    Days(i64),
+    Weeks(i64),
+    Months(i64),
+    Years(i64),
+    BusinessDays(i64),
+}
+
+impl Date {
+    fn calendar(&self, reform: Reform) -> Calendar {
+        Calendar {
+            year: self.year,
+            month: self.month,
+            reform,
+        }
+    }
+
+    pub fn day_number(&self, reform: Reform) -> i64 {
+        self.calendar(reform).day_number(self.day)
+    }
+
+    pub fn from_day_number(number: i64, reform: Reform) -> Option<Date> {
+        let (cal, day) = Calendar::from_day_number(number, reform)?;
+        Some(Date {
+            year: cal.year,
+            month: cal.month,
+            day,
+        })
+    }
+
+    pub fn day_of_week(&self, reform: Reform) -> DayOfWeek {
+        self.calendar(reform).get_day_of_week(self.day)
+    }
+
+    /// Parses YYYY-MM-DD, rejecting days that do not exist under the reform.
+    pub fn parse(s: &str, reform: Reform) -> Result<Date, String> {
+        let invalid = || format!("Invalid date: {} (use YYYY-MM-DD)", s);
+        let parts: Vec<&str> = s.split('-').collect();
+        if parts.len() != 3 {
+            return Err(invalid());
+        }
+        let year: u16 = parts[0].parse().map_err(|_| invalid())?;
+        let month: u8 = parts[1].parse().map_err(|_| invalid())?;
+        let day: u32 = parts[2].parse().map_err(|_| invalid())?;
+        if year == 0 || !(1..=12).contains(&month) {
+            return Err(invalid());
+        }
+        let date = Date {
+            year,
+            month: month - 1,
+            day,
+        };
+        if !date.calendar(reform).days().contains(&day) {
+            return Err(format!("{} does not exist", s));
+        }
+        Ok(date)
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month + 1, self.day)
+    }
+}
+
+impl FromStr for Offset {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!(
+                "Invalid offset: {} (use a signed number followed by d, w, m, y or b, e.g. +45d)",
+                s
+            )
+        };
+        let unit = s.chars().last().ok_or_else(invalid)?;
+        let amount: i64 = s[..s.len() - unit.len_utf8()]
+            .parse()
+            .map_err(|_| invalid())?;
+        match unit {
+            'd' => Ok(Offset::Days(amount)),
+            'w' => Ok(Offset::Weeks(amount)),
+            'm' => Ok(Offset::Months(amount)),
+            'y' => Ok(Offset::Years(amount)),
+            'b' => Ok(Offset::BusinessDays(amount)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+// Business days among the day numbers start..end: whole weeks count five,
+// the remaining days are checked one by one, and holidays falling on
+// weekdays are subtracted.
+fn business_days_between(start: i64, end: i64, holidays: Option<Country>) -> i64 {
+    if start >= end {
+        return 0;
+    }
+    let is_weekday = |number: i64| {
+        !matches!(
+            DayOfWeek::from_day_number(number),
+            DayOfWeek::Sat | DayOfWeek::Sun
+        )
+    };
+    let len = end - start;
+    let mut count = len / 7 * 5 + (end - len % 7..end).filter(|&n| is_weekday(n)).count() as i64;
+    if let Some(country) = holidays {
+        // Holidays are dated in the Gregorian calendar, whatever the reform.
+        let year = |number| Date::from_day_number(number, Reform::Gregorian).map(|d| d.year);
+        let first = year(start).unwrap_or(1);
+        let last = year(end - 1).unwrap_or(u16::MAX);
+        for year in first..=last {
+            let mut numbers: Vec<i64> = country
+                .dated(year)
+                .into_iter()
+                .map(|(number, _)| number)
+                .collect();
+            // Two holidays can share a date, like Ascension Day and Labour Day.
+            numbers.dedup();
+            count -= numbers
+                .iter()
+                .filter(|&&n| (start..end).contains(&n) && is_weekday(n))
+                .count() as i64;
+        }
+    }
+    count
+}
+
+/// Days from `from` to `to`; negative when `to` is earlier.
+pub fn diff_days(from: &Date, to: &Date, reform: Reform) -> i64 {
+    to.day_number(reform) - from.day_number(reform)
+}
+
+/// Weekdays from `from` up to but not including `to` that are not holidays
+/// of the given country; negative when `to` is earlier.
+pub fn diff_business_days(
+    from: &Date,
+    to: &Date,
+    holidays: Option<Country>,
+    reform: Reform,
+) -> i64 {
+    let (from, to) = (from.day_number(reform), to.day_number(reform));
+    let count = business_days_between(from.min(to), from.max(to), holidays);
+    if from <= to {
+        count
+    } else {
+        -count
+    }
+}
+
+/// Moves a date by the offset. Month and year offsets keep the day of the
+/// month where it exists and otherwise use the last earlier day. Returns
+/// None when the result falls outside years 1 to 65535.
+pub fn shift(
+    date: &Date,
+    offset: Offset,
+    holidays: Option<Country>,
+    reform: Reform,
+) -> Option<Date> {
+    match offset {
+        Offset::Days(n) => Date::from_day_number(date.day_number(reform).checked_add(n)?, reform),
+        Offset::Weeks(n) => Date::from_day_number(
+            date.day_number(reform).checked_add(n.checked_mul(7)?)?,
+            reform,
+        ),
+        Offset::Months(n) => {
+            let months = (date.year as i64 * 12 + date.month as i64).checked_add(n)?;
+            let year = months.div_euclid(12);
+            if !(1..=u16::MAX as i64).contains(&year) {
+                return None;
+            }
+            let cal = Calendar {
+                year: year as u16,
+                month: months.rem_euclid(12) as u8,
+                reform,
+            };
+            let days = cal.days();
+            let day = days
+                .iter()
+                .rev()
+                .find(|&&d| d <= date.day)
+                .unwrap_or(&days[0]);
+            Some(Date {
+                year: cal.year,
+                month: cal.month,
+                day: *day,
+            })
+        }
+        Offset::Years(n) => shift(date, Offset::Months(n.checked_mul(12)?), holidays, reform),
+        Offset::BusinessDays(n) => {
+            let step = n.signum();
+            let mut number = date.day_number(reform);
+            let mut remaining = n.checked_abs()?;
+            // No week has more than five business days, so jumping a week
+            // for every five still to go never overshoots. At least one is
+            // left for the day-by-day walk, which ends on a business day.
+            while remaining > 5 {
+                let weeks = (remaining - 1) / 5;
+                let target = number.checked_add(weeks.checked_mul(7 * step)?)?;
+                let (start, end) = if step > 0 {
+                    (number + 1, target + 1)
+                } else {
+                    (target, number)
+                };
+                remaining -= business_days_between(start, end, holidays);
+                number = target;
+            }
+            while remaining > 0 {
+                number += step;
+                remaining -= business_days_between(number, number + 1, holidays);
+            }
+            Date::from_day_number(number, reform)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(s: &str) -> Date {
+        Date::parse(s, Reform::default()).unwrap()
+    }
+
+    #[test]
+    fn check_diff() {
+        let reform = Reform::default();
+        assert_eq!(
+            diff_days(&date("2024-03-01"), &date("2024-09-15"), reform),
+            198
+        );
+        assert_eq!(
+            diff_days(&date("2024-09-15"), &date("2024-03-01"), reform),
+            -198
+        );
+        assert_eq!(
+            diff_days(&date("1752-09-02"), &date("1752-09-14"), reform),
+            1
+        );
+    }
+
+    #[test]
+    fn check_business_days() {
+        // Friday to the following Friday, with Monday being Memorial Day.
+        let reform = Reform::default();
+        let from = date("2024-05-24");
+        let to = date("2024-05-31");
+        assert_eq!(diff_business_days(&from, &to, None, reform), 5);
+        assert_eq!(diff_business_days(&from, &to, Some(Country::Us), reform), 4);
+        assert_eq!(
+            shift(&from, Offset::BusinessDays(1), Some(Country::Us), reform),
+            Some(date("2024-05-28"))
+        );
+    }
+
+    #[test]
+    fn check_business_days_over_years() {
+        let reform = Reform::default();
+        let from = date("2024-01-01");
+        let to = date("2025-01-01");
+        // 262 weekdays in 2024, 11 of them US federal holidays.
+        assert_eq!(diff_business_days(&from, &to, None, reform), 262);
+        assert_eq!(
+            diff_business_days(&from, &to, Some(Country::Us), reform),
+            251
+        );
+        assert_eq!(
+            diff_business_days(&to, &from, Some(Country::Us), reform),
+            -251
+        );
+        assert_eq!(
+            shift(&from, Offset::BusinessDays(251), Some(Country::Us), reform),
+            Some(date("2024-12-31"))
+        );
+        assert_eq!(
+            shift(&to, Offset::BusinessDays(-251), Some(Country::Us), reform),
+            Some(date("2024-01-02"))
+        );
+        // From a Sunday, whole weeks back would land on another Sunday.
+        assert_eq!(
+            shift(&date("2024-03-17"), Offset::BusinessDays(-10), None, reform),
+            Some(date("2024-03-04"))
+        );
+        assert_eq!(
+            shift(&from, Offset::BusinessDays(999_999_999), None, reform),
+            None
+        );
+    }
+
+    #[test]
+    fn check_shift() {
+        let reform = Reform::default();
+        let d = date("2024-03-01");
+        assert_eq!(
+            shift(&d, "+45d".parse().unwrap(), None, reform),
+            Some(date("2024-04-15"))
+        );
+        assert_eq!(
+            shift(&d, "-1w".parse().unwrap(), None, reform),
+            Some(date("2024-02-23"))
+        );
+        assert_eq!(
+            shift(&date("2024-01-31"), Offset::Months(1), None, reform),
+            Some(date("2024-02-29"))
+        );
+        assert_eq!(
+            shift(&date("2024-02-29"), Offset::Years(1), None, reform),
+            Some(date("2025-02-28"))
+        );
+        assert_eq!(
+            shift(&date("0001-01-01"), Offset::Days(-1), None, reform),
+            None
+        );
+    }
+}
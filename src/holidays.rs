@@ -0,0 +1,289 @@
+use std::str::FromStr;
+
+use crate::calendar::{Calendar, DayOfWeek, Reform};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Country {
+    Us,
+    Gb,
+    De,
+    In,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Holiday {
+    pub month: u8, //month starts from 0
+    pub day: u32,
+    pub name: &'static str,
+}
+
+impl FromStr for Country {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "us" => Ok(Country::Us),
+            "gb" | "uk" => Ok(Country::Gb),
+            "de" => Ok(Country::De),
+            "in" => Ok(Country::In),
+            _ => Err(format!(
+                "Unknown holiday calendar: {} (use us, gb, de or in)",
+                s
+            )),
+        }
+    }
+}
+
+impl Holiday {
+    fn fixed(month: u8, day: u32, name: &'static str) -> Self {
+        Holiday { month, day, name }
+    }
+
+    // n starts from 1; n == 0 means the last such weekday of the month.
+    fn nth_weekday(year: u16, month: u8, weekday: DayOfWeek, n: u32, name: &'static str) -> Self {
+        let cal = gregorian(year, month);
+        let first = (1..=7)
+            .find(|&d| cal.get_day_of_week(d) == weekday)
+            .unwrap();
+        let day = if n == 0 {
+            let last_weeks = (cal.days_in_month() - first) / 7;
+            first + last_weeks * 7
+        } else {
+            first + (n - 1) * 7
+        };
+        Holiday { month, day, name }
+    }
+
+    fn from_easter(year: u16, offset: i32, name: &'static str) -> Self {
+        let (month, day) = easter(year);
+        let mut cal = gregorian(year, month);
+        let mut day = day as i32 + offset;
+        while day <= 0 {
+            cal = cal.prev_month();
+            day += cal.days_in_month() as i32;
+        }
+        while day > cal.days_in_month() as i32 {
+            day -= cal.days_in_month() as i32;
+            cal = cal.next_month();
+        }
+        Holiday {
+            month: cal.month,
+            day: day as u32,
+            name,
+        }
+    }
+}
+
+// Holiday rules follow each country's civil calendar, which is Gregorian
+// whatever reform the calendar is printed with.
+fn gregorian(year: u16, month: u8) -> Calendar {
+    Calendar {
+        year,
+        month,
+        reform: Reform::Gregorian,
+    }
+}
+
+/// Western Easter Sunday in the Gregorian calendar as (month, day) with the
+/// month starting from 0.
+pub fn easter(year: u16) -> (u8, u32) {
+    let y = year as u32;
+    let a = y % 19;
+    let b = y / 100;
+    let c = y % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    ((month - 1) as u8, day)
+}
+
+impl Country {
+    /// Holidays of this country in the given year of the printed calendar,
+    /// sorted by date. They are worked out in the Gregorian calendar and
+    /// then placed under the reform, so near a cutover a holiday can move
+    /// into another month or year, or fall on a day that does not exist.
+    pub fn holidays(&self, year: u16, reform: Reform) -> Vec<Holiday> {
+        let january = Calendar {
+            year,
+            month: 0,
+            reform,
+        };
+        let december = Calendar {
+            year,
+            month: 11,
+            reform,
+        };
+        let gregorian_year =
+            |number| Calendar::from_day_number(number, Reform::Gregorian).map(|(cal, _)| cal.year);
+        let first = gregorian_year(january.day_number(january.days()[0])).unwrap_or(1);
+        let last = gregorian_year(december.day_number(*december.days().last().unwrap()))
+            .unwrap_or(u16::MAX);
+        let mut holidays: Vec<Holiday> = (first..=last)
+            .flat_map(|y| self.dated(y))
+            .filter_map(|(number, holiday)| {
+                let (cal, day) = Calendar::from_day_number(number, reform)?;
+                (cal.year == year).then_some(Holiday {
+                    month: cal.month,
+                    day,
+                    name: holiday.name,
+                })
+            })
+            .collect();
+        holidays.sort_by_key(|h| (h.month, h.day));
+        holidays
+    }
+
+    /// Holidays of this country in the given Gregorian year with their day
+    /// numbers, sorted by date. Each one is listed from the year its current
+    /// rule took effect; for Germany and India that is no earlier than the
+    /// founding of the present state.
+    pub(crate) fn dated(&self, year: u16) -> Vec<(i64, Holiday)> {
+        let weekday = |month, weekday, n, name| Holiday::nth_weekday(year, month, weekday, n, name);
+        let easter = |offset, name| Holiday::from_easter(year, offset, name);
+        let rules: Vec<(u16, Holiday)> = match self {
+            Country::Us => vec![
+                (1870, Holiday::fixed(0, 1, "New Year's Day")),
+                (
+                    1986,
+                    weekday(0, DayOfWeek::Mon, 3, "Martin Luther King Jr. Day"),
+                ),
+                (1971, weekday(1, DayOfWeek::Mon, 3, "Presidents' Day")),
+                (1971, weekday(4, DayOfWeek::Mon, 0, "Memorial Day")),
+                (2021, Holiday::fixed(5, 19, "Juneteenth")),
+                (1870, Holiday::fixed(6, 4, "Independence Day")),
+                (1894, weekday(8, DayOfWeek::Mon, 1, "Labor Day")),
+                (1971, weekday(9, DayOfWeek::Mon, 2, "Columbus Day")),
+                (1978, Holiday::fixed(10, 11, "Veterans Day")),
+                (1942, weekday(10, DayOfWeek::Thu, 4, "Thanksgiving Day")),
+                (1870, Holiday::fixed(11, 25, "Christmas Day")),
+            ],
+            Country::Gb => vec![
+                (1974, Holiday::fixed(0, 1, "New Year's Day")),
+                (1871, easter(-2, "Good Friday")),
+                (1871, easter(1, "Easter Monday")),
+                (
+                    1978,
+                    weekday(4, DayOfWeek::Mon, 1, "Early May Bank Holiday"),
+                ),
+                (1971, weekday(4, DayOfWeek::Mon, 0, "Spring Bank Holiday")),
+                (1971, weekday(7, DayOfWeek::Mon, 0, "Summer Bank Holiday")),
+                (1871, Holiday::fixed(11, 25, "Christmas Day")),
+                (1871, Holiday::fixed(11, 26, "Boxing Day")),
+            ],
+            Country::De => vec![
+                (1949, Holiday::fixed(0, 1, "New Year's Day")),
+                (1949, easter(-2, "Good Friday")),
+                (1949, easter(1, "Easter Monday")),
+                (1949, Holiday::fixed(4, 1, "Labour Day")),
+                (1949, easter(39, "Ascension Day")),
+                (1949, easter(50, "Whit Monday")),
+                (1990, Holiday::fixed(9, 3, "German Unity Day")),
+                (1949, Holiday::fixed(11, 25, "Christmas Day")),
+                (1949, Holiday::fixed(11, 26, "Second Day of Christmas")),
+            ],
+            Country::In => vec![
+                (1950, Holiday::fixed(0, 26, "Republic Day")),
+                (1950, easter(-2, "Good Friday")),
+                (1947, Holiday::fixed(7, 15, "Independence Day")),
+                (1950, Holiday::fixed(9, 2, "Gandhi Jayanti")),
+                (1950, Holiday::fixed(11, 25, "Christmas Day")),
+            ],
+        };
+        let mut holidays: Vec<(i64, Holiday)> = rules
+            .into_iter()
+            .filter(|(since, _)| year >= *since)
+            .map(|(_, holiday)| {
+                (
+                    gregorian(year, holiday.month).day_number(holiday.day),
+                    holiday,
+                )
+            })
+            .collect();
+        holidays.sort_by_key(|(number, _)| *number);
+        holidays
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_easter() {
+        assert_eq!(easter(2024), (2, 31));
+        assert_eq!(easter(2025), (3, 20));
+        assert_eq!(easter(2038), (3, 25));
+        assert_eq!(easter(1700), (3, 11));
+    }
+
+    #[test]
+    fn check_julian_display() {
+        // Western Easter and Christmas, dated thirteen days earlier.
+        let holidays = Country::Gb.holidays(2024, Reform::Julian);
+        assert!(holidays.contains(&Holiday::fixed(2, 16, "Good Friday")));
+        assert!(holidays.contains(&Holiday::fixed(2, 19, "Easter Monday")));
+        assert!(holidays.contains(&Holiday::fixed(11, 12, "Christmas Day")));
+        // January 1, 2025 is still in the Julian 2024.
+        assert!(holidays.contains(&Holiday::fixed(11, 19, "New Year's Day")));
+        assert!(!holidays.contains(&Holiday::fixed(0, 1, "New Year's Day")));
+    }
+
+    #[test]
+    fn check_cutover_month() {
+        let check = |reform: &str, year, month, day, name| {
+            let reform: Reform = reform.parse().unwrap();
+            let holidays = Country::Us.holidays(year, reform);
+            assert!(holidays.contains(&Holiday::fixed(month, day, name)));
+            let cal = Calendar {
+                year,
+                month,
+                reform,
+            };
+            assert_eq!(cal.get_day_of_week(day), DayOfWeek::Mon);
+        };
+        // Monday, September 3, 1990 is still Julian August 21.
+        check("1990-09-14", 1990, 7, 21, "Labor Day");
+        check("2100-02-28", 2100, 1, 2, "Presidents' Day");
+        check("2100-02-28", 2100, 4, 31, "Memorial Day");
+    }
+
+    #[test]
+    fn check_nth_weekday() {
+        let holidays = Country::Us.holidays(2024, Reform::default());
+        assert!(holidays.contains(&Holiday::fixed(10, 28, "Thanksgiving Day")));
+        assert!(holidays.contains(&Holiday::fixed(4, 27, "Memorial Day")));
+    }
+
+    #[test]
+    fn check_introduction_years() {
+        let reform = Reform::default();
+        let mlk = |year| {
+            Country::Us
+                .holidays(year, reform)
+                .iter()
+                .any(|h| h.name == "Martin Luther King Jr. Day")
+        };
+        assert!(!mlk(1985));
+        assert!(mlk(1986));
+        assert!(Country::Us.holidays(1700, reform).is_empty());
+        assert!(Country::Gb.holidays(1700, reform).is_empty());
+        assert_eq!(Country::De.holidays(1989, reform).len(), 8);
+        assert_eq!(Country::De.holidays(1990, reform).len(), 9);
+    }
+
+    #[test]
+    fn check_easter_offsets() {
+        let holidays = Country::De.holidays(2024, Reform::default());
+        assert!(holidays.contains(&Holiday::fixed(2, 29, "Good Friday")));
+        assert!(holidays.contains(&Holiday::fixed(4, 9, "Ascension Day")));
+        assert!(holidays.contains(&Holiday::fixed(4, 20, "Whit Monday")));
+    }
+}
@@ -0,0 +1,13 @@
+//! Calendar arithmetic and terminal rendering used by the `calendar` binary.
+//!
+//! [`Calendar`] does the day counting for a single month and prints month
+//! grids; [`holidays`] and [`alt_calendar`] provide the data shown on top of
+//! the grid, and [`date_math`] builds date differences and offsets on the
+//! same day numbers.
+
+pub mod alt_calendar;
+pub mod calendar;
+pub mod date_math;
+pub mod holidays;
+
+pub use crate::calendar::{Calendar, DayOfWeek, PrintOptions, Reform};
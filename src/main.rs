@@ -1,60 +1,392 @@
-mod calendar;
-use std::{env, process::exit};
+mod cli;
+mod completions;
+use std::io::{self, Write};
+use std::{env, fs, process::exit, str::FromStr};
 
-use calendar::Calendar;
+use calendar::date_math::{self, Date, Offset};
+use calendar::{Calendar, PrintOptions, Reform};
 use chrono::{Datelike, Local};
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+    let mut options = PrintOptions::default();
+    let mut reform = Reform::default();
+
+    if args.len() > 1 && args[1] == cli::COMPLETIONS {
+        let shell = args.get(2).map(String::as_str).unwrap_or("");
+        match completions::script(shell) {
+            Some(script) => print!("{}", script),
+            None => {
+                println!("Usage: calendar completions bash|zsh|fish");
+                exit(1);
+            }
+        }
+        exit(0);
+    }
+
+    if let Some(flag) = cli::unknown_flag(&args[1..]) {
+        println!("Unknown option: {}", flag);
+        exit(1);
+    }
+
+    if let Some(pos) = args.iter().position(|a| a == cli::JSON) {
+        options.json = true;
+        args.remove(pos);
+    }
+
+    if let Some(pos) = args.iter().position(|a| a == cli::JULIAN) {
+        options.julian = true;
+        args.remove(pos);
+    }
+
+    let business = match args.iter().position(|a| a == cli::BUSINESS) {
+        Some(pos) => {
+            args.remove(pos);
+            true
+        }
+        None => false,
+    };
+
+    if let Some(value) = take_flag_value(
+        &mut args,
+        cli::HOLIDAYS,
+        "a country code (us, gb, de or in)",
+    ) {
+        options.holidays = Some(parse_or_exit(&value));
+    }
+
+    if let Some(value) = take_flag_value(
+        &mut args,
+        cli::REFORM,
+        "1582, 1752, gregorian, julian or a date",
+    ) {
+        reform = parse_or_exit(&value);
+    }
+
+    if let Some(value) = take_flag_value(&mut args, cli::ALT, "a calendar system (saka or islamic)")
+    {
+        options.alt_calendar = Some(parse_or_exit(&value));
+    }
+
+    let months_count = take_flag_value(&mut args, cli::MONTHS, "a number").map(|value| match value
+        .parse::<usize>(
+    ) {
+        Ok(n) if n > 0 => n,
+        _ => {
+            println!("The number of months must be a positive integer");
+            exit(1);
+        }
+    });
+    let from = take_flag_value(&mut args, cli::FROM, "a month as YYYY-MM")
+        .map(|value| parse_year_month(&value, reform));
+    let to = take_flag_value(&mut args, cli::TO, "a month as YYYY-MM")
+        .map(|value| parse_year_month(&value, reform));
+
+    if let Some(name) = cli::unknown_subcommand(&args[1..]) {
+        println!("Unknown command: {}", name);
+        exit(1);
+    }
+
+    if business && (args.len() < 2 || args[1] != cli::DIFF) {
+        print_usage();
+    }
+
+    if args.len() > 1 && (args[1] == cli::DIFF || args[1] == cli::SHIFT) {
+        if args.len() != 4 {
+            print_usage();
+        }
+        let from = or_exit(Date::parse(&args[2], reform));
+        if args[1] == cli::DIFF {
+            let to = or_exit(Date::parse(&args[3], reform));
+            let days = date_math::diff_days(&from, &to, reform);
+            let business_days =
+                || date_math::diff_business_days(&from, &to, options.holidays, reform);
+            if options.json {
+                println!(
+                    "{{\"from\":\"{}\",\"to\":\"{}\",\"days\":{},\"business_days\":{}}}",
+                    from,
+                    to,
+                    days,
+                    business_days()
+                );
+            } else if business {
+                println!("{}", business_days());
+            } else {
+                println!("{}", days);
+            }
+        } else {
+            let offset: Offset = parse_or_exit(&args[3]);
+            let Some(to) = date_math::shift(&from, offset, options.holidays, reform) else {
+                println!("Invalid range");
+                exit(1);
+            };
+            if options.json {
+                println!(
+                    "{{\"date\":\"{}\",\"weekday\":\"{}\"}}",
+                    to,
+                    to.day_of_week(reform)
+                );
+            } else {
+                println!("{}", to);
+            }
+        }
+        exit(0);
+    }
 
     let now = Local::now();
     let date = now.date_naive();
 
+    if args.len() > 1 && args[1] == cli::EXPORT {
+        if options.json || months_count.is_some() || from.is_some() || to.is_some() {
+            println!("--json, --months, --from and --to cannot be used with export");
+            exit(1);
+        }
+        let format = take_flag_value(&mut args, cli::FORMAT, "a format (html or pdf)")
+            .unwrap_or_else(|| "html".to_string());
+        if format != "html" && format != "pdf" {
+            println!("Unsupported export format: {} (use html or pdf)", format);
+            exit(1);
+        }
+        let month = take_flag_value(&mut args, cli::MONTH, "a month as YYYY-MM")
+            .map(|value| parse_year_month(&value, reform));
+        let year = take_flag_value(&mut args, cli::YEAR, "a year").map(|value| parse_year(&value));
+        let output = take_flag_value(&mut args, cli::OUTPUT_SHORT, "a file name")
+            .or_else(|| take_flag_value(&mut args, cli::OUTPUT, "a file name"));
+        if args.len() != 2 {
+            print_usage();
+        }
+        let months = match (month, year) {
+            (Some(_), Some(_)) => {
+                println!("--month and --year cannot be used together");
+                exit(1);
+            }
+            (Some(month), None) => vec![month],
+            (None, Some(year)) => Calendar::months_from(
+                Calendar {
+                    year,
+                    month: 0,
+                    reform,
+                },
+                12,
+            ),
+            (None, None) => vec![Calendar {
+                year: date.year() as u16,
+                month: now.month0() as u8,
+                reform,
+            }],
+        };
+        let bytes = if format == "pdf" {
+            export_pdf(&months, &options)
+        } else if months.len() == 1 {
+            months[0].to_html(&options).into_bytes()
+        } else {
+            println!("--year can only be exported as pdf");
+            exit(1);
+        };
+        match output {
+            Some(path) => {
+                if let Err(e) = fs::write(&path, bytes) {
+                    println!("Could not write {}: {}", path, e);
+                    exit(1);
+                }
+            }
+            None => {
+                if let Err(e) = io::stdout().write_all(&bytes) {
+                    println!("Could not write the export: {}", e);
+                    exit(1);
+                }
+            }
+        }
+        exit(0);
+    }
+
+    if months_count.is_some() || from.is_some() || to.is_some() {
+        if options.alt_calendar.is_some() {
+            println!("--alt can only be used with a single month");
+            exit(1);
+        }
+        let months = match (from, to) {
+            (Some(from), Some(to)) => {
+                if months_count.is_some() || args.len() > 1 {
+                    println!("--from and --to cannot be combined with --months or a year");
+                    exit(1);
+                }
+                let Some(months) = Calendar::months_between(from, &to) else {
+                    println!("--from must not be after --to");
+                    exit(1);
+                };
+                months
+            }
+            (None, None) => {
+                let start = match args.len() {
+                    1 => Calendar {
+                        year: date.year() as u16,
+                        month: now.month0() as u8,
+                        reform,
+                    },
+                    2 => Calendar {
+                        year: parse_year(&args[1]),
+                        month: 0,
+                        reform,
+                    },
+                    3 => Calendar {
+                        year: parse_year(&args[1]),
+                        month: parse_month(&args[2]) - 1,
+                        reform,
+                    },
+                    _ => print_usage(),
+                };
+                Calendar::months_from(start, months_count.unwrap())
+            }
+            _ => {
+                println!("--from and --to must be used together");
+                exit(1);
+            }
+        };
+        Calendar::print_months(&months, &options);
+        exit(0);
+    }
+
     if args.len() == 1 {
         let cal = Calendar {
             year: date.year() as u16,
             month: now.month0() as u8,
+            reform,
         };
-        cal.print();
+        if options.alt_calendar.is_some() {
+            cal.print_single_month(&options);
+        } else {
+            cal.print(&options);
+        }
         exit(0);
     }
 
     if args.len() != 3 && args.len() != 2 {
-        println!("Usage: calendar <year> <month>");
-        println!("Or: calendar <year>");
+        print_usage();
+    }
+    let year = parse_year(&args[1]);
+
+    if args.len() == 3 {
+        let month = parse_month(&args[2]);
+        // The three-month view needs the months on either side to exist.
+        if (year == 1 && month == 1) || (year == u16::MAX && month == 12) {
+            println!("Invalid range");
+            exit(1);
+        }
+
+        let cal = Calendar {
+            year,
+            month: month - 1,
+            reform,
+        };
+        if options.alt_calendar.is_some() {
+            cal.print_single_month(&options);
+        } else {
+            cal.print(&options);
+        }
+    } else if options.alt_calendar.is_some() {
+        println!("--alt can only be used with a single month");
         exit(1);
+    } else {
+        Calendar::print_entire_year(year, reform, &options);
     }
-    let year: u16 = match args[1].parse() {
+}
+
+fn print_usage() -> ! {
+    println!("Usage: calendar [--json] [--julian] [--reform <date>] [--holidays <country>] [--alt <system>] <year> <month>");
+    println!("Or: calendar [--json] [--julian] [--reform <date>] [--holidays <country>] <year>");
+    println!("Or: calendar [--months <n>] [<year> [<month>]]");
+    println!("Or: calendar --from <YYYY-MM> --to <YYYY-MM>");
+    println!("Or: calendar diff [--business] [--holidays <country>] <YYYY-MM-DD> <YYYY-MM-DD>");
+    println!("Or: calendar shift [--holidays <country>] <YYYY-MM-DD> <offset, e.g. +45d, -2w, 3m, 1y, 10b>");
+    println!(
+        "Or: calendar export [--format html|pdf] [--month <YYYY-MM> | --year <year>] [-o <file>]"
+    );
+    println!("Or: calendar completions bash|zsh|fish");
+    println!();
+    println!("Dates before the Gregorian reform are Julian. The default reform is 1752,");
+    println!("when Great Britain switched, so 1583 to 1752 print as Julian unless");
+    println!("--reform gives another date, or gregorian for Gregorian rules throughout.");
+    exit(1);
+}
+
+#[cfg(feature = "pdf")]
+fn export_pdf(months: &[Calendar], options: &PrintOptions) -> Vec<u8> {
+    Calendar::to_pdf(months, options)
+}
+
+#[cfg(not(feature = "pdf"))]
+fn export_pdf(_months: &[Calendar], _options: &PrintOptions) -> Vec<u8> {
+    println!("PDF export is not available in this build (rebuild with --features pdf)");
+    exit(1);
+}
+
+// Removes `flag` and the value following it from args, returning the value.
+// `needs` describes the value for the error when it is missing.
+fn take_flag_value(args: &mut Vec<String>, flag: &str, needs: &str) -> Option<String> {
+    let pos = args.iter().position(|a| a == flag)?;
+    if pos + 1 >= args.len() {
+        println!("{} needs {}", flag, needs);
+        exit(1);
+    }
+    let value = args.remove(pos + 1);
+    args.remove(pos);
+    Some(value)
+}
+
+fn parse_or_exit<T: FromStr<Err = String>>(value: &str) -> T {
+    or_exit(value.parse())
+}
+
+fn or_exit<T>(result: Result<T, String>) -> T {
+    match result {
+        Ok(v) => v,
+        Err(e) => {
+            println!("{}", e);
+            exit(1);
+        }
+    }
+}
+
+fn parse_year(value: &str) -> u16 {
+    let year: u16 = match value.parse() {
         Ok(v) => v,
         Err(_) => {
             println!("The year must be an integer");
             exit(1);
         }
     };
-    if year < 1583 {
+    if year == 0 {
         println!("Invalid range");
         exit(1);
     }
+    year
+}
 
-    if args.len() == 3 {
-        let month: u8 = match args[2].parse() {
-            Ok(v) => v,
-            Err(_) => {
-                println!("The month must be an integer");
-                exit(1);
-            }
-        };
-        if !(1..=12).contains(&month) {
-            println!("Invalid range");
+// Returns the month starting from 1.
+fn parse_month(value: &str) -> u8 {
+    let month: u8 = match value.parse() {
+        Ok(v) => v,
+        Err(_) => {
+            println!("The month must be an integer");
             exit(1);
         }
+    };
+    if !(1..=12).contains(&month) {
+        println!("Invalid range");
+        exit(1);
+    }
+    month
+}
 
-        let cal = Calendar {
-            year,
-            month: month - 1,
-        };
-        cal.print();
-    } else {
-        Calendar::print_entire_year(year);
+fn parse_year_month(value: &str, reform: Reform) -> Calendar {
+    let Some((year, month)) = value.split_once('-') else {
+        println!("Months must be given as YYYY-MM");
+        exit(1);
+    };
+    Calendar {
+        year: parse_year(year),
+        month: parse_month(month) - 1,
+        reform,
     }
 }